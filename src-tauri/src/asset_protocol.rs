@@ -0,0 +1,232 @@
+//! Streaming, range-capable responder for the `nova-asset://` scheme.
+//!
+//! Backs previews of multi-hundred-MB logs, binary hex views, and
+//! generated/virtual documents by serving bounded chunks instead of
+//! loading whole files into memory.
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Mutex;
+
+use tauri::http::{Request, Response, StatusCode};
+use tauri::{AppHandle, Manager};
+
+/// Chunk size used when no `Range` header is present.
+const DEFAULT_CHUNK: u64 = 1024 * 1024;
+
+/// Workspace roots a request path is allowed to resolve into.
+pub struct ScopedRoots(Mutex<Vec<std::path::PathBuf>>);
+
+impl Default for ScopedRoots {
+  fn default() -> Self {
+    Self(Mutex::new(Vec::new()))
+  }
+}
+
+impl ScopedRoots {
+  /// Canonicalizes `root` before storing it, so it compares equal to the
+  /// canonicalized candidates in `resolve`/`contains` even when the root
+  /// itself sits behind a symlink (e.g. macOS `/tmp`, a symlinked home
+  /// directory or project checkout). Falls back to the raw path if the
+  /// root doesn't exist yet.
+  pub fn add(&self, root: std::path::PathBuf) {
+    let root = root.canonicalize().unwrap_or(root);
+    self.0.lock().unwrap().push(root);
+  }
+
+  fn resolve(&self, rel: &str) -> Option<std::path::PathBuf> {
+    for root in self.0.lock().unwrap().iter() {
+      let candidate = root.join(rel);
+      if let Ok(canonical) = candidate.canonicalize() {
+        if canonical.starts_with(root) {
+          return Some(canonical);
+        }
+      }
+    }
+    None
+  }
+
+  /// Returns whether an already-absolute `path` falls under one of the
+  /// registered workspace roots. Used by callers (e.g. the `nova://` deep
+  /// link handler) outside the protocol responder itself.
+  pub fn contains(&self, path: &std::path::Path) -> bool {
+    let Ok(canonical) = path.canonicalize() else {
+      return false;
+    };
+    self
+      .0
+      .lock()
+      .unwrap()
+      .iter()
+      .any(|root| canonical.starts_with(root))
+  }
+}
+
+/// In-memory documents registered by a command (e.g. a generated diff or
+/// preview buffer) that aren't backed by a file on disk.
+#[derive(Default)]
+pub struct VirtualBuffers(Mutex<HashMap<String, (String, Vec<u8>)>>);
+
+impl VirtualBuffers {
+  pub fn register(&self, id: String, content_type: String, bytes: Vec<u8>) {
+    self.0.lock().unwrap().insert(id, (content_type, bytes));
+  }
+
+  fn get(&self, id: &str) -> Option<(String, Vec<u8>)> {
+    self.0.lock().unwrap().get(id).cloned()
+  }
+}
+
+enum Source {
+  File(std::path::PathBuf, String, u64),
+  Virtual(String, Vec<u8>),
+}
+
+/// Handles one `nova-asset://<path-or-id>` request, mapping it to a scoped
+/// file or a registered virtual buffer and returning a `206 Partial Content`
+/// slice sized to the request's `Range` header (or `DEFAULT_CHUNK`).
+pub fn handle(app: &AppHandle, request: &Request<Vec<u8>>) -> Response<Vec<u8>> {
+  let target = request.uri().path().trim_start_matches('/');
+  let target = percent_encoding::percent_decode_str(target).decode_utf8_lossy();
+
+  let source = if let Some((content_type, bytes)) = app.state::<VirtualBuffers>().get(&target) {
+    Some(Source::Virtual(content_type, bytes))
+  } else if let Some(path) = app.state::<ScopedRoots>().resolve(&target) {
+    file_source(&path)
+  } else {
+    None
+  };
+
+  let Some(source) = source else {
+    return Response::builder()
+      .status(StatusCode::NOT_FOUND)
+      .body(Vec::new())
+      .unwrap();
+  };
+
+  let total = match &source {
+    Source::File(_, _, len) => *len,
+    Source::Virtual(_, bytes) => bytes.len() as u64,
+  };
+
+  let header_range = request.headers().get("range").and_then(|v| v.to_str().ok());
+  let (start, end) = match header_range {
+    Some(header) => match parse_range(header) {
+      Some(range) => range,
+      None => return range_not_satisfiable(total),
+    },
+    None => (0, DEFAULT_CHUNK - 1),
+  };
+  let end = end.min(total.saturating_sub(1));
+
+  if start > end || (total > 0 && start >= total) {
+    return range_not_satisfiable(total);
+  }
+
+  let (content_type, slice) = match source {
+    Source::Virtual(content_type, bytes) if start < total => {
+      (content_type, bytes[start as usize..=end as usize].to_vec())
+    }
+    Source::Virtual(content_type, _) => (content_type, Vec::new()),
+    Source::File(path, content_type, _) if start < total => {
+      (content_type, read_range(&path, start, end).unwrap_or_default())
+    }
+    Source::File(_, content_type, _) => (content_type, Vec::new()),
+  };
+
+  Response::builder()
+    .status(StatusCode::PARTIAL_CONTENT)
+    .header("Content-Type", content_type)
+    .header("Content-Length", slice.len().to_string())
+    .header("Content-Range", format!("bytes {start}-{end}/{total}"))
+    .header("Accept-Ranges", "bytes")
+    .body(slice)
+    .unwrap()
+}
+
+/// Rejects a request whose `Range` can't be satisfied against `total` bytes,
+/// per RFC 7233 (`416 Range Not Satisfiable`).
+fn range_not_satisfiable(total: u64) -> Response<Vec<u8>> {
+  Response::builder()
+    .status(StatusCode::RANGE_NOT_SATISFIABLE)
+    .header("Content-Range", format!("bytes */{total}"))
+    .body(Vec::new())
+    .unwrap()
+}
+
+fn file_source(path: &std::path::Path) -> Option<Source> {
+  let len = std::fs::metadata(path).ok()?.len();
+  let content_type = mime_guess::from_path(path)
+    .first_or_octet_stream()
+    .to_string();
+  Some(Source::File(path.to_path_buf(), content_type, len))
+}
+
+/// Seeks to `start` and reads the inclusive `[start, end]` byte range without
+/// loading the rest of the file.
+fn read_range(path: &std::path::Path, start: u64, end: u64) -> Option<Vec<u8>> {
+  let mut file = std::fs::File::open(path).ok()?;
+  file.seek(SeekFrom::Start(start)).ok()?;
+  let mut buf = vec![0u8; (end - start + 1) as usize];
+  file.read_exact(&mut buf).ok()?;
+  Some(buf)
+}
+
+/// Parses an HTTP `Range: bytes=START-END` header into an inclusive byte
+/// span, rejecting an inverted range (`end < start`) outright.
+fn parse_range(header: &str) -> Option<(u64, u64)> {
+  let spec = header.strip_prefix("bytes=")?;
+  let (start, end) = spec.split_once('-')?;
+  let start: u64 = start.parse().ok()?;
+  let end = if end.is_empty() {
+    start + DEFAULT_CHUNK - 1
+  } else {
+    end.parse().ok()?
+  };
+  (start <= end).then_some((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[cfg(unix)]
+  #[test]
+  fn add_canonicalizes_a_symlinked_root() {
+    let base =
+      std::env::temp_dir().join(format!("nova-scoped-roots-test-{}", std::process::id()));
+    let real = base.join("real");
+    let link = base.join("link");
+    std::fs::create_dir_all(&real).unwrap();
+    std::fs::write(real.join("file.txt"), b"hi").unwrap();
+    std::os::unix::fs::symlink(&real, &link).unwrap();
+
+    let roots = ScopedRoots::default();
+    roots.add(link.clone());
+
+    assert!(roots.resolve("file.txt").is_some());
+    assert!(roots.contains(&real.join("file.txt")));
+
+    std::fs::remove_dir_all(&base).unwrap();
+  }
+
+  #[test]
+  fn parse_range_accepts_well_formed_span() {
+    assert_eq!(parse_range("bytes=0-99"), Some((0, 99)));
+  }
+
+  #[test]
+  fn parse_range_defaults_the_end_to_a_chunk_boundary() {
+    assert_eq!(parse_range("bytes=10-"), Some((10, 10 + DEFAULT_CHUNK - 1)));
+  }
+
+  #[test]
+  fn parse_range_rejects_inverted_span() {
+    assert_eq!(parse_range("bytes=500-10"), None);
+  }
+
+  #[test]
+  fn parse_range_rejects_malformed_header() {
+    assert_eq!(parse_range("not-a-range"), None);
+  }
+}
@@ -0,0 +1,302 @@
+//! Backend command surface: workspace indexing, content search, and
+//! chunked file reads. Indexing and search run off the command thread (search
+//! fans out over a parallel walker's thread pool) so the UI stays responsive
+//! on large repositories; `read_file_chunked` reads synchronously.
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use ignore::{WalkBuilder, WalkState};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexedFile {
+  pub path: String,
+  pub len: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexProgress {
+  pub indexed: usize,
+  pub done: bool,
+}
+
+/// Walks `root` respecting `.gitignore` on a background thread, streaming
+/// batches of discovered files back as `index-files` events (each followed by
+/// an `index-progress` count) so the UI can render incrementally instead of
+/// waiting for the whole tree.
+#[tauri::command]
+pub fn index_workspace(app: AppHandle, root: String) {
+  std::thread::spawn(move || {
+    let mut indexed = 0usize;
+    let mut batch = Vec::new();
+
+    for entry in WalkBuilder::new(&root).hidden(false).build() {
+      let Ok(entry) = entry else { continue };
+      if entry.file_type().is_some_and(|t| t.is_file()) {
+        let len = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        batch.push(IndexedFile {
+          path: entry.path().to_string_lossy().into_owned(),
+          len,
+        });
+        indexed += 1;
+
+        if batch.len() >= 200 {
+          let _ = app.emit("index-files", std::mem::take(&mut batch));
+          let _ = app.emit(
+            "index-progress",
+            IndexProgress {
+              indexed,
+              done: false,
+            },
+          );
+        }
+      }
+    }
+
+    if !batch.is_empty() {
+      let _ = app.emit("index-files", batch);
+    }
+    let _ = app.emit(
+      "index-progress",
+      IndexProgress {
+        indexed,
+        done: true,
+      },
+    );
+  });
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchOptions {
+  #[serde(default)]
+  pub case_sensitive: bool,
+  #[serde(default)]
+  pub whole_word: bool,
+  #[serde(default)]
+  pub context_lines: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchMatch {
+  pub path: String,
+  pub line: usize,
+  pub column: usize,
+  pub text: String,
+  pub context: Vec<String>,
+}
+
+/// Registry of in-flight searches keyed by `search_id`, so a new query can
+/// cancel a stale one instead of letting both race to emit results.
+#[derive(Default)]
+pub struct SearchTokens(Mutex<HashMap<String, Arc<AtomicBool>>>);
+
+impl SearchTokens {
+  fn start(&self, id: &str) -> Arc<AtomicBool> {
+    let mut tokens = self.0.lock().unwrap();
+    if let Some(previous) = tokens.get(id) {
+      previous.store(true, Ordering::Relaxed);
+    }
+    let token = Arc::new(AtomicBool::new(false));
+    tokens.insert(id.to_string(), token.clone());
+    token
+  }
+}
+
+/// Scans `root` for `query`, fanning the directory walk across a thread
+/// pool (one file read per worker, `ripgrep`-style) and streaming matches
+/// back as `search-matches` events; aborts early if a newer search with the
+/// same `search_id` has been started.
+#[tauri::command]
+pub fn search_content(
+  app: AppHandle,
+  search_id: String,
+  root: String,
+  query: String,
+  opts: SearchOptions,
+) {
+  let tokens = app.state::<SearchTokens>();
+  let cancelled = tokens.start(&search_id);
+
+  std::thread::spawn(move || {
+    let needle = if opts.case_sensitive {
+      query.clone()
+    } else {
+      query.to_lowercase()
+    };
+
+    WalkBuilder::new(&root)
+      .hidden(false)
+      .build_parallel()
+      .run(|| {
+        let app = app.clone();
+        let cancelled = cancelled.clone();
+        let needle = needle.clone();
+        let opts = opts.clone();
+
+        Box::new(move |entry| {
+          if cancelled.load(Ordering::Relaxed) {
+            return WalkState::Quit;
+          }
+          search_entry(&app, entry, &needle, &opts, &cancelled);
+          if cancelled.load(Ordering::Relaxed) {
+            WalkState::Quit
+          } else {
+            WalkState::Continue
+          }
+        })
+      });
+
+    let _ = app.emit("search-done", search_id);
+  });
+}
+
+/// Scans a single walked entry for `needle`, emitting one `search-matches`
+/// event per occurrence found.
+fn search_entry(
+  app: &AppHandle,
+  entry: Result<ignore::DirEntry, ignore::Error>,
+  needle: &str,
+  opts: &SearchOptions,
+  cancelled: &AtomicBool,
+) {
+  let Ok(entry) = entry else { return };
+  if !entry.file_type().is_some_and(|t| t.is_file()) {
+    return;
+  }
+
+  let Ok(content) = std::fs::read_to_string(entry.path()) else {
+    return;
+  };
+  let lines: Vec<&str> = content.lines().collect();
+
+  for (idx, line) in lines.iter().enumerate() {
+    if cancelled.load(Ordering::Relaxed) {
+      return;
+    }
+    let haystack = if opts.case_sensitive {
+      line.to_string()
+    } else {
+      line.to_lowercase()
+    };
+    let offsets = find_matches(&haystack, needle, opts.whole_word);
+    if offsets.is_empty() {
+      continue;
+    }
+
+    let start = idx.saturating_sub(opts.context_lines);
+    let end = (idx + opts.context_lines + 1).min(lines.len());
+    let context: Vec<String> = lines[start..end].iter().map(|s| s.to_string()).collect();
+
+    for byte_offset in offsets {
+      let _ = app.emit(
+        "search-matches",
+        SearchMatch {
+          path: entry.path().to_string_lossy().into_owned(),
+          line: idx + 1,
+          column: char_column(line, byte_offset, !opts.case_sensitive) + 1,
+          text: line.to_string(),
+          context: context.clone(),
+        },
+      );
+    }
+  }
+}
+
+/// Returns the byte offset of every occurrence of `needle` in `haystack`,
+/// so a line containing it more than once reports one match per occurrence.
+fn find_matches(haystack: &str, needle: &str, whole_word: bool) -> Vec<usize> {
+  if needle.is_empty() {
+    return Vec::new();
+  }
+  let mut matches = Vec::new();
+  let mut start = 0;
+  while let Some(pos) = haystack[start..].find(needle) {
+    let pos = start + pos;
+    if !whole_word || is_word_boundary(haystack, pos, needle.len()) {
+      matches.push(pos);
+    }
+    start = pos + needle.chars().next().map_or(1, char::len_utf8);
+  }
+  matches
+}
+
+/// Converts a byte offset into the haystack (the lowercased `line` when
+/// `folded` is set) into a char column over the original `line`, so a
+/// non-ASCII prefix (or case folding that changes byte length, e.g. `İ` ->
+/// `i̇`) doesn't throw off where an editor frontend places the highlight.
+fn char_column(line: &str, byte_offset: usize, folded: bool) -> usize {
+  let mut pos = 0;
+  let mut column = 0;
+  for c in line.chars() {
+    if pos >= byte_offset {
+      break;
+    }
+    pos += if folded {
+      c.to_lowercase().map(char::len_utf8).sum()
+    } else {
+      c.len_utf8()
+    };
+    column += 1;
+  }
+  column
+}
+
+fn is_word_boundary(haystack: &str, pos: usize, len: usize) -> bool {
+  let before = haystack[..pos].chars().next_back();
+  let after = haystack[pos + len..].chars().next();
+  !before.is_some_and(|c| c.is_alphanumeric() || c == '_')
+    && !after.is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Reads `len` bytes of `path` starting at `offset` without loading the
+/// whole file into memory.
+#[tauri::command]
+pub fn read_file_chunked(path: String, offset: u64, len: u64) -> Result<Vec<u8>, String> {
+  let mut file = std::fs::File::open(&path).map_err(|e| e.to_string())?;
+  file.seek(SeekFrom::Start(offset)).map_err(|e| e.to_string())?;
+  let mut buf = vec![0u8; len as usize];
+  let read = file.read(&mut buf).map_err(|e| e.to_string())?;
+  buf.truncate(read);
+  Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn find_matches_reports_every_occurrence() {
+    assert_eq!(find_matches("foo foo foo", "foo", false), vec![0, 4, 8]);
+  }
+
+  #[test]
+  fn find_matches_returns_empty_for_no_hit() {
+    assert!(find_matches("hello world", "foo", false).is_empty());
+  }
+
+  #[test]
+  fn find_matches_whole_word_skips_partial_hits() {
+    assert_eq!(find_matches("foobar foo", "foo", true), vec![7]);
+  }
+
+  #[test]
+  fn find_matches_whole_word_allows_punctuation_boundaries() {
+    assert_eq!(find_matches("(foo, foo)", "foo", true), vec![1, 6]);
+  }
+
+  #[test]
+  fn char_column_counts_chars_not_bytes() {
+    // "café xoxo": the match on "xoxo" starts at byte offset 6 (the 2-byte
+    // 'é' pushes it past its char index of 5).
+    assert_eq!(char_column("café xoxo", 6, false), 5);
+  }
+
+  #[test]
+  fn char_column_at_start_of_line_is_zero() {
+    assert_eq!(char_column("foo", 0, false), 0);
+  }
+}
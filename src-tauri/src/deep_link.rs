@@ -0,0 +1,146 @@
+//! Parsing and dispatch for the `nova://` deep-link scheme.
+//!
+//! Supported forms:
+//!   nova://open?path=/abs/path/to/file#L42        -> `deep-link-open`
+//!   nova://open?path=/abs/path/to/file#L42,C7      -> `deep-link-open` with column
+//!   nova://workspace/<id>                          -> `deep-link-workspace`
+//!   nova://diff?left=/abs/a&right=/abs/b           -> `deep-link-diff`
+
+use std::mem;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::asset_protocol::ScopedRoots;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeepLinkOpen {
+  pub path: String,
+  pub line: Option<u32>,
+  pub col: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeepLinkDiff {
+  pub left: String,
+  pub right: String,
+}
+
+#[derive(Debug, Clone)]
+enum Command {
+  Open(DeepLinkOpen),
+  Workspace(String),
+  Diff(DeepLinkDiff),
+}
+
+/// Deep links that arrived while no `main` window existed to receive them.
+/// Drained by `flush_pending`.
+#[derive(Default)]
+pub struct PendingLinks(Mutex<Vec<String>>);
+
+impl PendingLinks {
+  fn push(&self, url: String) {
+    self.0.lock().unwrap().push(url);
+  }
+
+  fn drain(&self) -> Vec<String> {
+    mem::take(&mut self.0.lock().unwrap())
+  }
+}
+
+/// Entry point for both the launch URL and URLs delivered to an
+/// already-running instance: dispatches immediately when the `main` window
+/// exists, otherwise buffers the URL until `flush_pending` runs.
+pub fn handle_url(app: &AppHandle, url: String) {
+  if app.get_webview_window("main").is_some() {
+    dispatch(app, &url);
+  } else {
+    app.state::<PendingLinks>().push(url);
+  }
+}
+
+/// Flushes any links buffered while no `main` window existed. Call once the
+/// frontend signals readiness.
+pub fn flush_pending(app: &AppHandle) {
+  for url in app.state::<PendingLinks>().drain() {
+    dispatch(app, &url);
+  }
+}
+
+fn dispatch(app: &AppHandle, raw: &str) {
+  match parse(app, raw) {
+    Some(Command::Open(payload)) => {
+      let _ = app.emit("deep-link-open", payload);
+    }
+    Some(Command::Workspace(id)) => {
+      let _ = app.emit("deep-link-workspace", id);
+    }
+    Some(Command::Diff(payload)) => {
+      let _ = app.emit("deep-link-diff", payload);
+    }
+    None => eprintln!("ignoring malformed deep link: {raw}"),
+  }
+}
+
+fn parse(app: &AppHandle, raw: &str) -> Option<Command> {
+  let url = url::Url::parse(raw).ok()?;
+  if url.scheme() != "nova" {
+    return None;
+  }
+
+  match url.host_str()? {
+    "open" => {
+      let path = query_param(&url, "path")?;
+      let path = sanitize_path(app, &path)?;
+      let (line, col) = parse_fragment(url.fragment());
+      Some(Command::Open(DeepLinkOpen { path, line, col }))
+    }
+    "workspace" => {
+      let id = url.path().trim_start_matches('/').to_string();
+      (!id.is_empty()).then_some(Command::Workspace(id))
+    }
+    "diff" => {
+      let left = sanitize_path(app, &query_param(&url, "left")?)?;
+      let right = sanitize_path(app, &query_param(&url, "right")?)?;
+      Some(Command::Diff(DeepLinkDiff { left, right }))
+    }
+    _ => None,
+  }
+}
+
+fn query_param(url: &url::Url, key: &str) -> Option<String> {
+  url
+    .query_pairs()
+    .find(|(k, _)| k == key)
+    .map(|(_, v)| v.into_owned())
+}
+
+/// Rejects `path` segments that climb outside their root via `..`, and
+/// rejects any path that doesn't resolve under a registered workspace root
+/// — deep links are externally triggerable, so an arbitrary absolute path
+/// (e.g. `~/.ssh/id_rsa`) must never reach the frontend.
+fn sanitize_path(app: &AppHandle, path: &str) -> Option<String> {
+  if path.split('/').any(|segment| segment == "..") {
+    return None;
+  }
+  if !app.state::<ScopedRoots>().contains(std::path::Path::new(path)) {
+    return None;
+  }
+  Some(path.to_string())
+}
+
+/// Normalizes a `#Ln` or `#Ln,Cm` fragment into a numeric (line, column) pair.
+fn parse_fragment(fragment: Option<&str>) -> (Option<u32>, Option<u32>) {
+  let Some(rest) = fragment.and_then(|f| f.strip_prefix('L')) else {
+    return (None, None);
+  };
+
+  match rest.split_once(',') {
+    Some((line, col)) => (
+      line.parse().ok(),
+      col.strip_prefix('C').unwrap_or(col).parse().ok(),
+    ),
+    None => (rest.parse().ok(), None),
+  }
+}
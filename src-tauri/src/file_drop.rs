@@ -0,0 +1,88 @@
+//! Native file-drop ingestion: expands dropped directories and filters by
+//! extension before handing paths to the frontend.
+
+use serde::Serialize;
+use tauri::{Emitter, WebviewWindow};
+
+/// Extensions the editor knows how to open. Files outside this set are
+/// silently dropped from the payload.
+const OPENABLE_EXTENSIONS: &[&str] = &[
+  "rs", "toml", "json", "md", "txt", "js", "ts", "tsx", "jsx", "html", "css", "yaml", "yml",
+];
+
+/// How far to expand a dropped directory.
+#[derive(Clone, Copy)]
+pub enum Expansion {
+  /// Only the directory's immediate children.
+  OneLevel,
+  /// Every file in the subtree.
+  Recursive,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FilesDropped {
+  paths: Vec<String>,
+}
+
+/// Registers `files-dropped`/hover/enter/leave forwarding for `window`'s
+/// native drag-and-drop events.
+pub fn register(window: &WebviewWindow, expansion: Expansion) {
+  let window = window.clone();
+  window.clone().on_window_event(move |event| {
+    let tauri::WindowEvent::DragDrop(drag) = event else {
+      return;
+    };
+
+    match drag {
+      tauri::DragDropEvent::Enter { paths, .. } => {
+        let _ = window.emit("files-drag-enter", paths.len());
+      }
+      tauri::DragDropEvent::Over { .. } => {
+        let _ = window.emit("files-drag-hover", ());
+      }
+      tauri::DragDropEvent::Leave => {
+        let _ = window.emit("files-drag-leave", ());
+      }
+      tauri::DragDropEvent::Drop { paths, .. } => {
+        let expanded = expand_paths(paths, expansion);
+        let _ = window.emit("files-dropped", FilesDropped { paths: expanded });
+      }
+      _ => {}
+    }
+  });
+}
+
+fn expand_paths(paths: &[std::path::PathBuf], expansion: Expansion) -> Vec<String> {
+  let mut out = Vec::new();
+  for path in paths {
+    if path.is_dir() {
+      expand_dir(path, expansion, &mut out);
+    } else if is_openable(path) {
+      out.push(path.to_string_lossy().into_owned());
+    }
+  }
+  out
+}
+
+fn expand_dir(dir: &std::path::Path, expansion: Expansion, out: &mut Vec<String>) {
+  let Ok(entries) = std::fs::read_dir(dir) else {
+    return;
+  };
+  for entry in entries.flatten() {
+    let path = entry.path();
+    if path.is_dir() {
+      if matches!(expansion, Expansion::Recursive) {
+        expand_dir(&path, expansion, out);
+      }
+    } else if is_openable(&path) {
+      out.push(path.to_string_lossy().into_owned());
+    }
+  }
+}
+
+fn is_openable(path: &std::path::Path) -> bool {
+  path
+    .extension()
+    .and_then(|ext| ext.to_str())
+    .is_some_and(|ext| OPENABLE_EXTENSIONS.contains(&ext))
+}
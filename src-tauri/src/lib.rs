@@ -1,13 +1,143 @@
+//! Shared editor backend, linked by both the desktop `main.rs` entry point
+//! and the mobile `tauri::mobile_entry_point` below. The desktop-only
+//! single-instance plugin is gated behind `#[cfg(desktop)]`; everything else
+//! runs on both targets. No global-shortcut plugin is registered.
+//!
+//! TODO(mobile): the source split here is not sufficient on its own —
+//! Cargo.toml still needs `crate-type = ["staticlib", "cdylib", "rlib"]`,
+//! the `nova_editor_lib` lib name `main.rs` depends on, and
+//! tauri-plugin-single-instance demoted to a desktop-only target
+//! dependency. See the TODO in `main.rs`.
+
+mod asset_protocol;
+mod commands;
+mod deep_link;
+mod file_drop;
+
+use tauri_plugin_deep_link::DeepLinkExt;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-  tauri::Builder::default()
+  let mut builder = tauri::Builder::default();
+
+  #[cfg(desktop)]
+  {
+    builder = builder.plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+      on_new_instance(app, argv, cwd);
+    }));
+  }
+
+  builder
     .plugin(tauri_plugin_fs::init())
     .plugin(tauri_plugin_dialog::init())
-    .setup(|_app| {
+    .plugin(tauri_plugin_deep_link::init())
+    .manage(deep_link::PendingLinks::default())
+    .manage(asset_protocol::ScopedRoots::default())
+    .manage(asset_protocol::VirtualBuffers::default())
+    .manage(commands::SearchTokens::default())
+    .invoke_handler(tauri::generate_handler![
+      frontend_ready,
+      register_workspace_root,
+      register_virtual_buffer,
+      commands::index_workspace,
+      commands::search_content,
+      commands::read_file_chunked,
+    ])
+    .register_uri_scheme_protocol("nova-asset", |ctx, request| {
+      asset_protocol::handle(ctx.app_handle(), &request)
+    })
+    .setup(|app| {
+      use tauri::Manager;
+
       // Window setup and additional configuration
       println!("Nova Editor starting up...");
+
+      for url in app.deep_link().get_current()?.unwrap_or_default() {
+        deep_link::handle_url(app.handle(), url.to_string());
+      }
+
+      let handle = app.handle().clone();
+      app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+          deep_link::handle_url(&handle, url.to_string());
+        }
+      });
+
+      if let Some(window) = app.get_webview_window("main") {
+        file_drop::register(&window, file_drop::Expansion::OneLevel);
+      }
+
       Ok(())
     })
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }
+
+/// Signals that the frontend has mounted and can receive deep links
+/// buffered while no `main` window existed.
+#[tauri::command]
+fn frontend_ready(app: tauri::AppHandle) {
+  deep_link::flush_pending(&app);
+}
+
+/// Allows `nova-asset://` to resolve relative paths under `root`.
+#[tauri::command]
+fn register_workspace_root(app: tauri::AppHandle, root: String) {
+  use tauri::Manager;
+  app
+    .state::<asset_protocol::ScopedRoots>()
+    .add(std::path::PathBuf::from(root));
+}
+
+/// Registers an in-memory document (e.g. a generated diff) so it can be
+/// fetched from `nova-asset://<id>` without touching disk.
+#[tauri::command]
+fn register_virtual_buffer(app: tauri::AppHandle, id: String, content_type: String, bytes: Vec<u8>) {
+  use tauri::Manager;
+  app
+    .state::<asset_protocol::VirtualBuffers>()
+    .register(id, content_type, bytes);
+}
+
+/// Handles a second `nova-editor` invocation forwarded to this already-running
+/// instance: resolves any path arguments against the caller's cwd, focuses
+/// the main window, and hands the paths to the frontend.
+#[cfg(desktop)]
+fn on_new_instance(app: &tauri::AppHandle, argv: Vec<String>, cwd: String) {
+  use tauri::{Emitter, Manager};
+
+  let mut paths = Vec::new();
+  for arg in argv.into_iter().skip(1) {
+    if arg.starts_with("nova://") {
+      deep_link::handle_url(app, arg);
+    } else {
+      paths.push(resolve_path_arg(&arg, &cwd));
+    }
+  }
+
+  if let Some(window) = app.get_webview_window("main") {
+    let _ = window.unminimize();
+    let _ = window.set_focus();
+  }
+
+  if !paths.is_empty() {
+    let _ = app.emit("open-paths", paths);
+  }
+}
+
+/// Canonicalizes a single argv entry against `cwd`, falling back to the
+/// joined (non-canonicalized) path if the target doesn't exist yet.
+#[cfg(desktop)]
+fn resolve_path_arg(arg: &str, cwd: &str) -> String {
+  let candidate = std::path::Path::new(arg);
+  let absolute = if candidate.is_absolute() {
+    candidate.to_path_buf()
+  } else {
+    std::path::Path::new(cwd).join(candidate)
+  };
+  absolute
+    .canonicalize()
+    .unwrap_or(absolute)
+    .to_string_lossy()
+    .into_owned()
+}
@@ -0,0 +1,13 @@
+// Prevents additional console window on Windows in release, DO NOT REMOVE!!
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+// TODO(mobile): this split only covers the source layout. `tauri android/ios
+// init` is still blocked until Cargo.toml gets crate-type = ["staticlib",
+// "cdylib", "rlib"], the `nova_editor_lib` lib name, and
+// tauri-plugin-single-instance moved to a desktop-only target dependency (it
+// isn't available on mobile targets and would otherwise stay an
+// unconditional dependency). Track and land that manifest change before
+// treating mobile support as done.
+fn main() {
+  nova_editor_lib::run();
+}